@@ -1,10 +1,17 @@
 mod list;
 
+use std::cmp::Ordering;
+use std::iter::Flatten;
+use std::iter::Peekable;
+use std::ops::Bound;
+use std::ops::RangeBounds;
+use std::slice;
+
 pub use list::AbsoluteOrdering;
-pub use list::List;
+pub use list::BArrayVec;
 
 pub struct Node<T, const N: usize> {
-	items: List<T, N>,
+	items: BArrayVec<T, N>,
 
 	next: Option<Box<Self>>,
 }
@@ -30,7 +37,7 @@ const _: () = {
 
 	impl<T: Debug, const N: usize> Debug for LinkedLists<T, N> {
 		fn fmt(&self, f: &mut Formatter) -> Result {
-			let mut next = self.root.as_ref();
+			let mut next = self.root.as_deref();
 
 			let mut f = f.debug_list();
 
@@ -48,80 +55,895 @@ const _: () = {
 impl<T, const N: usize> Node<T, N> {
 	pub fn new(item: T) -> Self {
 		Self {
-			items: List::new(item),
+			items: BArrayVec::new(item),
 
 			next: None,
 		}
 	}
+	// INTERNAL: consumes the node, handing back its `items` and its successor individually. A
+	// plain `let Self { items, next } = self` would be a partial move out of a type with a `Drop`
+	// impl, which the compiler rejects, so `next` is taken through `&mut self` instead and `items`
+	// is read out manually; `self` is then forgotten rather than dropped, since by that point
+	// `next` is already `None` and `items` has already been moved out from under it
+	fn into_parts(mut self) -> (BArrayVec<T, N>, Option<Box<Self>>) {
+		let next = self.next.take();
+		let items = unsafe { std::ptr::read(&self.items) };
+
+		std::mem::forget(self);
+
+		(items, next)
+	}
+}
+
+// The derived Drop would recurse into `next`, `next.next`, ... and overflow the stack on a long
+// enough chain (easy to hit via `from_sorted_unchecked` on a few million elements). Unlink the
+// chain iteratively instead, so each `Box<Node>` drops its own `items` without recursing into its
+// successor.
+impl<T, const N: usize> Drop for Node<T, N> {
+	fn drop(&mut self) {
+		let mut next = self.next.take();
+
+		while let Some(mut node) = next {
+			next = node.next.take();
+		}
+	}
 }
 
 impl<T, const N: usize> Node<T, N>
 where
 	T: Ord,
 {
-	pub fn insert(&mut self, item: T) {
-		match self.items.insert(item) {
-			Err((item, AbsoluteOrdering::Less)) => {
-				let mut node = Node::new(item);
+	/// Returns `true` if a new node had to be linked into the chain, i.e. any directory built
+	/// over this chain needs rebuilding
+	pub fn insert(&mut self, item: T) -> bool {
+		let mut node = self;
+		let mut item = item;
 
-				std::mem::swap(self, &mut node);
+		loop {
+			match node.items.insert(item) {
+				Err((overflow, AbsoluteOrdering::Less)) => {
+					let mut new_node = Node::new(overflow);
 
-				self.next = Some(Box::new(node))
-			}
-			Err((item, AbsoluteOrdering::Greater)) | Ok(Some(item)) => {
-				if let Some(next) = self.next.as_deref_mut() {
-					next.insert(item);
-				} else {
-					self.next = Some(Box::new(Node::new(item)))
+					std::mem::swap(node, &mut new_node);
+
+					node.next = Some(Box::new(new_node));
+
+					return true;
 				}
+				Err((overflow, AbsoluteOrdering::Greater)) | Ok(Some(overflow)) => {
+					if node.next.is_none() {
+						node.next = Some(Box::new(Node::new(overflow)));
+
+						return true;
+					}
+
+					item = overflow;
+					node = node.next.as_deref_mut().unwrap();
+				}
+				Ok(None) => return false,
 			}
-			Ok(None) => (),
 		}
 	}
 	pub fn find(&self, item: &T) -> Option<usize> {
-		match self.items.find(item) {
-			Ok(index) => index,
-			Err(AbsoluteOrdering::Greater) => self.next.as_ref()?.find(item),
-			Err(AbsoluteOrdering::Less) => None,
+		let mut node = self;
+
+		loop {
+			match node.items.find(item) {
+				Ok(index) => return index,
+				Err(AbsoluteOrdering::Greater) => match node.next.as_deref() {
+					Some(next) => node = next,
+					None => return None,
+				},
+				Err(AbsoluteOrdering::Less) => return None,
+			}
 		}
 	}
 	pub fn contains(&self, item: &T) -> bool {
 		self.find(item).is_some()
 	}
+	// INTERNAL: walk the chain starting at `slot` looking for `item`; `slot` is the
+	// `Option<Box<Node>>` that owns the node currently under consideration (`LinkedLists::root`,
+	// or some earlier node's `next`), so when that node empties out this can drop it by
+	// overwriting `slot` with whatever its own `next` was, whether that's another node or nothing
+	// - this covers a tail node emptying too, which has no successor to swap itself into
+	pub(crate) fn remove(slot: &mut Option<Box<Self>>, item: &T) -> Option<T> {
+		let mut slot = slot;
+
+		loop {
+			let node = slot.as_mut()?;
+
+			match node.items.find(item) {
+				Ok(Some(index)) => {
+					let (item, emptied) = node.items._remove(index);
+
+					if emptied {
+						// `node.items`'s one remaining slot was already moved out by `_remove`
+						// above, but its `len` still (necessarily, since it's `NonZeroU8`) reports
+						// it occupied - letting the node drop normally here would double-drop that
+						// slot now that `BArrayVec` has its own `Drop` impl, so pull the node apart
+						// through `into_parts` and forget the degenerate `items` instead
+						let old = slot.take().unwrap();
+						let (items, next) = (*old).into_parts();
+
+						std::mem::forget(items);
+
+						*slot = next;
+					}
+
+					return Some(item);
+				}
+				Ok(None) => return None,
+				Err(AbsoluteOrdering::Greater) => {}
+				Err(AbsoluteOrdering::Less) => return None,
+			}
+
+			slot = &mut slot.as_mut().unwrap().next;
+		}
+	}
 }
 
 pub struct LinkedLists<T, const N: usize> {
-	root: Option<Node<T, N>>,
+	root: Option<Box<Node<T, N>>>,
+
+	// A flat index of pointers straight to each node in the chain, in order, so `find`/`insert`/
+	// `range` can binary-search (comparing against each node's live `min()`, or its `max()` for
+	// `range`) their way to the owning node in O(log(n/N)) instead of walking the `next` chain
+	// node by node. Rebuilt wholesale whenever a mutation may have changed the chain's shape (a
+	// node split or emptied) - the chain is boxed node by node (including the root, as of this field's
+	// introduction) so the pointers stay valid across moves of the `LinkedLists` itself.
+	directory: Vec<*mut Node<T, N>>,
 
 	len: usize,
 }
 
+// The pointers in `directory` only ever alias nodes already owned by `root` through the boxed
+// `next` chain, never anything outside of `self`, so `LinkedLists` can be `Send`/`Sync` under
+// exactly the bounds on `T` it would need if `directory` held indices or `Box<Node<T, N>>` instead
+// of raw pointers.
+unsafe impl<T: Send, const N: usize> Send for LinkedLists<T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for LinkedLists<T, N> {}
+
+// Never called; only compiles if `LinkedLists<T, N>` is `Send`/`Sync` whenever `T` is, guarding
+// against `directory`'s raw pointers silently taking those impls away again.
+#[allow(dead_code)]
+fn assert_linked_lists_send_sync<T: Send + Sync, const N: usize>() {
+	fn assert<U: Send + Sync>() {}
+
+	assert::<LinkedLists<T, N>>();
+}
+
 impl<T, const N: usize> LinkedLists<T, N> {
 	pub const fn new() -> Self {
-		Self { root: None, len: 0 }
+		Self {
+			root: None,
+			directory: Vec::new(),
+			len: 0,
+		}
 	}
 	pub const fn len(&self) -> usize {
 		self.len
 	}
+	/// Packs already-sorted (ascending) `items` directly into fully-populated nodes, the last node
+	/// holding whatever remains
+	///
+	/// This is much cheaper than repeated [`insert`](Self::insert), which may rescan and shift a
+	/// node per element; the caller is trusted to have sorted `items` beforehand, this does not
+	/// check.
+	pub fn from_sorted_unchecked(items: Vec<T>) -> Self {
+		let len = items.len();
+		let mut items = items.into_iter();
+		let mut nodes = Vec::new();
+
+		while let Some(first) = items.next() {
+			let mut node_items = BArrayVec::new(first);
+
+			for item in items.by_ref().take(N - 1) {
+				unsafe { node_items._push(item).unwrap_unchecked() };
+			}
+
+			nodes.push(Node {
+				items: node_items,
+				next: None,
+			});
+		}
+
+		let root = nodes.into_iter().rev().fold(None, |next, mut node| {
+			node.next = next;
+
+			Some(Box::new(node))
+		});
+
+		let mut this = Self {
+			root,
+			directory: Vec::new(),
+			len,
+		};
+
+		this.rebuild_directory();
+
+		this
+	}
+	// INTERNAL: flatten the `next` chain into `directory` so lookups can binary-search it
+	fn rebuild_directory(&mut self) {
+		self.directory.clear();
+
+		let mut next = self.root.as_deref_mut();
+
+		while let Some(node) = next {
+			let ptr: *mut Node<T, N> = node;
+
+			self.directory.push(ptr);
+
+			next = unsafe { (*ptr).next.as_deref_mut() };
+		}
+	}
+	pub fn iter(&self) -> Iter<'_, T> {
+		let mut slices = Vec::new();
+		let mut next = self.root.as_deref();
+
+		while let Some(node) = next {
+			slices.push(&*node.items);
+
+			next = node.next.as_deref();
+		}
+
+		Iter {
+			inner: slices.into_iter().flatten(),
+			len: self.len,
+		}
+	}
+}
+
+/// An iterator over the items of a [`LinkedLists`], in ascending order
+pub struct Iter<'a, T> {
+	inner: Flatten<std::vec::IntoIter<&'a [T]>>,
+	len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let item = self.inner.next();
+
+		if item.is_some() {
+			self.len -= 1;
+		}
+
+		item
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let item = self.inner.next_back();
+
+		if item.is_some() {
+			self.len -= 1;
+		}
+
+		item
+	}
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+	fn len(&self) -> usize {
+		self.len
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a LinkedLists<T, N> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An owning iterator over the items of a [`LinkedLists`], in ascending order
+pub struct IntoIter<T, const N: usize> {
+	inner: Flatten<std::vec::IntoIter<list::IntoIter<T, N>>>,
+	len: usize,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let item = self.inner.next();
+
+		if item.is_some() {
+			self.len -= 1;
+		}
+
+		item
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let item = self.inner.next_back();
+
+		if item.is_some() {
+			self.len -= 1;
+		}
+
+		item
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+	fn len(&self) -> usize {
+		self.len
+	}
+}
+
+impl<T, const N: usize> IntoIterator for LinkedLists<T, N> {
+	type Item = T;
+	type IntoIter = IntoIter<T, N>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		let len = self.len;
+		let mut iters = Vec::new();
+		let mut next = self.root.map(|node| *node);
+
+		while let Some(node) = next {
+			let (items, rest) = node.into_parts();
+
+			next = rest.map(|next| *next);
+
+			iters.push(items.into_iter());
+		}
+
+		IntoIter {
+			inner: iters.into_iter().flatten(),
+			len,
+		}
+	}
 }
 
 impl<T, const N: usize> LinkedLists<T, N>
 where
 	T: Ord,
 {
+	// INTERNAL: binary-search the directory for the node that would contain `item`, i.e. the
+	// last node whose `min()` is not greater than `item` (where several nodes' `min()` equal
+	// `item`, any one of them); `None` means `item`, if present at all, comes before every node
+	// currently in the chain
+	fn locate(&self, item: &T) -> Option<*mut Node<T, N>> {
+		let index = match self
+			.directory
+			.binary_search_by(|&node| unsafe { (*node).items.min() }.cmp(item))
+		{
+			Ok(index) => index,
+			Err(0) => return None,
+			Err(index) => index - 1,
+		};
+
+		Some(self.directory[index])
+	}
+	// INTERNAL: binary-search the directory for the first node that could hold an element >=
+	// `start` (> `start` when `excluded`), i.e. the first node whose `max()` is not less than
+	// (not less than or equal to) `start`; unlike `locate` this must never skip past a node that
+	// merely *starts* before `start` but still reaches it, so it compares against each node's
+	// `max()` instead of its `min()`. `None` means no element currently in the chain lies past
+	// `start`
+	fn locate_range_start(&self, start: &T, excluded: bool) -> Option<*mut Node<T, N>> {
+		let index = self.directory.partition_point(|&node| {
+			let max = unsafe { (*node).items.max() };
+
+			max < start || (excluded && max == start)
+		});
+
+		self.directory.get(index).copied()
+	}
 	pub fn insert(&mut self, item: T) {
-		if let Some(root) = self.root.as_mut() {
-			root.insert(item)
-		} else {
-			self.root = Some(Node::new(item));
-		}
+		let split = match self.locate(&item) {
+			Some(ptr) => unsafe { (*ptr).insert(item) },
+			None => {
+				let mut node = Box::new(Node::new(item));
+
+				node.next = self.root.take();
+				self.root = Some(node);
+
+				true
+			}
+		};
 
 		self.len += 1;
+
+		if split {
+			self.rebuild_directory();
+		}
 	}
 	pub fn find(&self, item: &T) -> Option<usize> {
-		self.root.as_ref()?.find(item)
+		let ptr = self.locate(item)?;
+
+		unsafe { (*ptr).find(item) }
 	}
 	pub fn contains(&self, item: &T) -> bool {
 		self.find(item).is_some()
 	}
+	// Unlike `insert`/`find`, this doesn't use `directory` to jump to the owning node - it walks
+	// the chain from `root` via `Node::remove`, and unconditionally rebuilds `directory` afterwards
+	// - so a removal is O(n/N) rather than O(log(n/N)). Locating the node to remove from the
+	// directory would still leave the rebuild, since removal can empty and splice out a node same
+	// as it can split one, so the win would be partial; left as the simpler of the two for now.
+	pub fn take(&mut self, item: &T) -> Option<T> {
+		let item = Node::remove(&mut self.root, item)?;
+
+		self.len -= 1;
+
+		self.rebuild_directory();
+
+		Some(item)
+	}
+	pub fn remove(&mut self, item: &T) -> bool {
+		self.take(item).is_some()
+	}
+	pub fn range<R>(&self, range: R) -> Range<'_, T, N, R>
+	where
+		R: RangeBounds<T>,
+	{
+		let first = match range.start_bound() {
+			Bound::Included(start) => self.locate_range_start(start, false).map(|ptr| unsafe { &*ptr }),
+			Bound::Excluded(start) => self.locate_range_start(start, true).map(|ptr| unsafe { &*ptr }),
+			Bound::Unbounded => self.root.as_deref(),
+		};
+
+		let front = match first {
+			Some(node) => {
+				// lower-bound search: the first index whose element is not excluded by `start`,
+				// so an `Included` duplicate run lands on its first occurrence rather than
+				// whatever arbitrary match a plain `binary_search` would return
+				let start_index = match range.start_bound() {
+					Bound::Included(start) => node.items.partition_point(|item| item < start),
+					Bound::Excluded(start) => node.items.partition_point(|item| item <= start),
+					Bound::Unbounded => 0,
+				};
+
+				node.items[start_index..].iter()
+			}
+			None => <&[T]>::default().iter(),
+		};
+
+		Range {
+			front,
+			next: first.and_then(|node| node.next.as_deref()),
+			range,
+			done: false,
+		}
+	}
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for LinkedLists<T, N> {
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut items: Vec<T> = iter.into_iter().collect();
+
+		items.sort_unstable();
+
+		Self::from_sorted_unchecked(items)
+	}
+}
+
+/// An iterator over a windowed selection of a [`LinkedLists`], in ascending order
+///
+/// Created by [`LinkedLists::range`].
+pub struct Range<'a, T, const N: usize, R> {
+	front: slice::Iter<'a, T>,
+	next: Option<&'a Node<T, N>>,
+	range: R,
+	done: bool,
+}
+
+impl<'a, T, const N: usize, R> Iterator for Range<'a, T, N, R>
+where
+	T: Ord,
+	R: RangeBounds<T>,
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+
+		loop {
+			if let Some(item) = self.front.next() {
+				let past_end = match self.range.end_bound() {
+					Bound::Included(end) => item > end,
+					Bound::Excluded(end) => item >= end,
+					Bound::Unbounded => false,
+				};
+
+				if past_end {
+					self.done = true;
+
+					return None;
+				}
+
+				return Some(item);
+			}
+
+			match self.next.take() {
+				Some(node) => {
+					self.front = node.items.iter();
+					self.next = node.next.as_deref();
+				}
+				None => {
+					self.done = true;
+
+					return None;
+				}
+			}
+		}
+	}
+}
+
+// These operations merge `self`/`other` pairwise, one equal element from each side at a time.
+// `LinkedLists` itself does allow duplicates (`insert` never dedups), and on such inputs the
+// results follow multiset rules: an element present `m` times in `self` and `n` times in `other`
+// appears `max(m, n)` times in the union, `min(m, n)` times in the intersection, `m - n` times (if
+// positive) in the difference and `|m - n|` times in the symmetric difference.
+impl<T, const N: usize> LinkedLists<T, N>
+where
+	T: Ord,
+{
+	pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, N> {
+		Union {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+	pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T, N> {
+		Intersection {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+	pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T, N> {
+		Difference {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+	pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, T, N> {
+		SymmetricDifference {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+}
+
+/// A lazy iterator over the elements present in either of two [`LinkedLists`]s, in ascending order
+///
+/// An element present `m` and `n` times in the two inputs is yielded `max(m, n)` times.
+///
+/// Created by [`LinkedLists::union`].
+pub struct Union<'a, T, const N: usize> {
+	a: Peekable<Iter<'a, T>>,
+	b: Peekable<Iter<'a, T>>,
+}
+
+impl<'a, T: Ord, const N: usize> Iterator for Union<'a, T, N> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<&'a T> {
+		match (self.a.peek(), self.b.peek()) {
+			(Some(x), Some(y)) => match x.cmp(y) {
+				Ordering::Less => self.a.next(),
+				Ordering::Greater => self.b.next(),
+				Ordering::Equal => {
+					self.b.next();
+					self.a.next()
+				}
+			},
+			(Some(_), None) => self.a.next(),
+			(None, Some(_)) => self.b.next(),
+			(None, None) => None,
+		}
+	}
+}
+
+/// A lazy iterator over the elements present in both of two [`LinkedLists`]s, in ascending order
+///
+/// An element present `m` and `n` times in the two inputs is yielded `min(m, n)` times.
+///
+/// Created by [`LinkedLists::intersection`].
+pub struct Intersection<'a, T, const N: usize> {
+	a: Peekable<Iter<'a, T>>,
+	b: Peekable<Iter<'a, T>>,
+}
+
+impl<'a, T: Ord, const N: usize> Iterator for Intersection<'a, T, N> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<&'a T> {
+		loop {
+			match (self.a.peek(), self.b.peek()) {
+				(Some(x), Some(y)) => match x.cmp(y) {
+					Ordering::Less => {
+						self.a.next();
+					}
+					Ordering::Greater => {
+						self.b.next();
+					}
+					Ordering::Equal => {
+						self.b.next();
+
+						return self.a.next();
+					}
+				},
+				_ => return None,
+			}
+		}
+	}
+}
+
+/// A lazy iterator over the elements present in the first of two [`LinkedLists`]s but not the
+/// second, in ascending order
+///
+/// An element present `m` times in the first and `n` times in the second input is yielded
+/// `m - n` times if that is positive.
+///
+/// Created by [`LinkedLists::difference`].
+pub struct Difference<'a, T, const N: usize> {
+	a: Peekable<Iter<'a, T>>,
+	b: Peekable<Iter<'a, T>>,
+}
+
+impl<'a, T: Ord, const N: usize> Iterator for Difference<'a, T, N> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<&'a T> {
+		loop {
+			match (self.a.peek(), self.b.peek()) {
+				(Some(x), Some(y)) => match x.cmp(y) {
+					Ordering::Less => return self.a.next(),
+					Ordering::Greater => {
+						self.b.next();
+					}
+					Ordering::Equal => {
+						self.a.next();
+						self.b.next();
+					}
+				},
+				(Some(_), None) => return self.a.next(),
+				(None, _) => return None,
+			}
+		}
+	}
+}
+
+/// A lazy iterator over the elements present in exactly one of two [`LinkedLists`]s, in ascending
+/// order
+///
+/// An element present `m` and `n` times in the two inputs is yielded `|m - n|` times.
+///
+/// Created by [`LinkedLists::symmetric_difference`].
+pub struct SymmetricDifference<'a, T, const N: usize> {
+	a: Peekable<Iter<'a, T>>,
+	b: Peekable<Iter<'a, T>>,
+}
+
+impl<'a, T: Ord, const N: usize> Iterator for SymmetricDifference<'a, T, N> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<&'a T> {
+		loop {
+			match (self.a.peek(), self.b.peek()) {
+				(Some(x), Some(y)) => match x.cmp(y) {
+					Ordering::Less => return self.a.next(),
+					Ordering::Greater => return self.b.next(),
+					Ordering::Equal => {
+						self.a.next();
+						self.b.next();
+					}
+				},
+				(Some(_), None) => return self.a.next(),
+				(None, Some(_)) => return self.b.next(),
+				(None, None) => return None,
+			}
+		}
+	}
+}
+
+impl<T: Ord + Clone, const N: usize> std::ops::BitOr for &LinkedLists<T, N> {
+	type Output = LinkedLists<T, N>;
+
+	fn bitor(self, other: Self) -> LinkedLists<T, N> {
+		// `union` already yields ascending output, so pack it straight into nodes instead of
+		// re-inserting (and potentially re-splitting) one element at a time
+		LinkedLists::from_sorted_unchecked(self.union(other).cloned().collect())
+	}
+}
+
+impl<T: Ord + Clone, const N: usize> std::ops::BitAnd for &LinkedLists<T, N> {
+	type Output = LinkedLists<T, N>;
+
+	fn bitand(self, other: Self) -> LinkedLists<T, N> {
+		// `intersection` already yields ascending output, so pack it straight into nodes instead
+		// of re-inserting (and potentially re-splitting) one element at a time
+		LinkedLists::from_sorted_unchecked(self.intersection(other).cloned().collect())
+	}
+}
+
+impl<T: Ord + Clone, const N: usize> std::ops::Sub for &LinkedLists<T, N> {
+	type Output = LinkedLists<T, N>;
+
+	fn sub(self, other: Self) -> LinkedLists<T, N> {
+		// `difference` already yields ascending output, so pack it straight into nodes instead of
+		// re-inserting (and potentially re-splitting) one element at a time
+		LinkedLists::from_sorted_unchecked(self.difference(other).cloned().collect())
+	}
+}
+
+impl<T: Ord + Clone, const N: usize> std::ops::BitXor for &LinkedLists<T, N> {
+	type Output = LinkedLists<T, N>;
+
+	fn bitxor(self, other: Self) -> LinkedLists<T, N> {
+		// `symmetric_difference` already yields ascending output, so pack it straight into nodes
+		// instead of re-inserting (and potentially re-splitting) one element at a time
+		LinkedLists::from_sorted_unchecked(self.symmetric_difference(other).cloned().collect())
+	}
+}
+
+#[test]
+fn test_set_operations() {
+	let a: LinkedLists<i32, 3> = [1, 2, 3, 4].into_iter().collect();
+	let b: LinkedLists<i32, 3> = [3, 4, 5, 6].into_iter().collect();
+
+	assert_eq!(
+		a.union(&b).copied().collect::<Vec<_>>(),
+		vec![1, 2, 3, 4, 5, 6]
+	);
+	assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), vec![3, 4]);
+	assert_eq!(a.difference(&b).copied().collect::<Vec<_>>(), vec![1, 2]);
+	assert_eq!(
+		a.symmetric_difference(&b).copied().collect::<Vec<_>>(),
+		vec![1, 2, 5, 6]
+	);
+}
+
+#[test]
+fn test_set_operators() {
+	let a: LinkedLists<i32, 3> = [1, 2, 3, 4].into_iter().collect();
+	let b: LinkedLists<i32, 3> = [3, 4, 5, 6].into_iter().collect();
+
+	assert_eq!(
+		(&a | &b).iter().copied().collect::<Vec<_>>(),
+		vec![1, 2, 3, 4, 5, 6]
+	);
+	assert_eq!((&a & &b).iter().copied().collect::<Vec<_>>(), vec![3, 4]);
+	assert_eq!((&a - &b).iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+	assert_eq!(
+		(&a ^ &b).iter().copied().collect::<Vec<_>>(),
+		vec![1, 2, 5, 6]
+	);
+}
+
+#[test]
+fn test_remove_until_empty() {
+	let mut list: LinkedLists<i32, 3> = (1..=4).collect();
+
+	assert_eq!(list.len(), 4);
+
+	assert_eq!(list.take(&4), Some(4));
+	assert!(!list.contains(&4));
+	assert_eq!(list.len(), 3);
+	assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+	for i in 1..=3 {
+		assert_eq!(list.take(&i), Some(i));
+	}
+
+	assert_eq!(list.len(), 0);
+	assert_eq!(list.iter().next(), None);
+}
+
+#[test]
+fn test_remove_emptied_node_drops_its_item_exactly_once() {
+	use std::cell::Cell;
+
+	struct DropCounter<'a>(i32, &'a Cell<usize>);
+
+	impl Drop for DropCounter<'_> {
+		fn drop(&mut self) {
+			self.1.set(self.1.get() + 1);
+		}
+	}
+
+	impl PartialEq for DropCounter<'_> {
+		fn eq(&self, other: &Self) -> bool {
+			self.0 == other.0
+		}
+	}
+
+	impl Eq for DropCounter<'_> {}
+
+	impl PartialOrd for DropCounter<'_> {
+		fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+			Some(self.cmp(other))
+		}
+	}
+
+	impl Ord for DropCounter<'_> {
+		fn cmp(&self, other: &Self) -> Ordering {
+			self.0.cmp(&other.0)
+		}
+	}
+
+	let count = Cell::new(0);
+	let key_count = Cell::new(0);
+
+	let mut list = LinkedLists::<DropCounter, 3>::new();
+
+	list.insert(DropCounter(1, &count));
+
+	// looked up by value, not identity, so the key can be its own (separately counted) instance
+	let taken = list.take(&DropCounter(1, &key_count)).unwrap();
+
+	assert_eq!(list.len(), 0);
+	assert_eq!(taken.0, 1);
+
+	drop(taken);
+
+	assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn test_range_duplicates() {
+	let list: LinkedLists<i32, 3> = [5, 5, 5, 5, 7, 9].into_iter().collect();
+
+	assert_eq!(
+		list.range(5..).copied().collect::<Vec<_>>(),
+		vec![5, 5, 5, 5, 7, 9]
+	);
+
+	let single: LinkedLists<i32, 8> = [5, 5, 5, 7].into_iter().collect();
+
+	assert_eq!(
+		single.range(5..).copied().collect::<Vec<_>>(),
+		vec![5, 5, 5, 7]
+	);
+}
+
+#[test]
+fn test_from_sorted_unchecked_round_trip() {
+	let items: Vec<i32> = (0..20).collect();
+
+	let list = LinkedLists::<i32, 3>::from_sorted_unchecked(items.clone());
+
+	assert_eq!(list.len(), items.len());
+	assert_eq!(list.iter().copied().collect::<Vec<_>>(), items);
+
+	for i in &items {
+		assert!(list.contains(i));
+	}
+}
+
+#[test]
+fn test_directory_lookup_across_nodes() {
+	let mut list = LinkedLists::<i32, 3>::new();
+
+	for i in 0..20 {
+		list.insert(i);
+	}
+
+	assert_eq!(list.len(), 20);
+
+	for i in 0..20 {
+		assert!(list.contains(&i));
+		assert!(list.find(&i).is_some());
+	}
+
+	assert!(!list.contains(&20));
+	assert!(!list.contains(&-1));
 }
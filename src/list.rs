@@ -5,7 +5,6 @@ use std::ops::Deref;
 use std::ptr;
 use std::slice;
 
-#[derive(Copy)]
 /// A nonempty list that is ordered
 pub struct BArrayVec<T, const N: usize> {
 	len: NonZeroU8,
@@ -48,6 +47,16 @@ impl<T, const N: usize> Deref for BArrayVec<T, N> {
 	}
 }
 
+impl<T, const N: usize> Drop for BArrayVec<T, N> {
+	fn drop(&mut self) {
+		let len = self.len();
+
+		for item in &mut self.buf[..len] {
+			unsafe { item.assume_init_drop() };
+		}
+	}
+}
+
 const _: () = {
 	use std::fmt::*;
 
@@ -106,7 +115,7 @@ impl<T, const N: usize> BArrayVec<T, N> {
 		self.len() == N
 	}
 	// INTERNAL: push item at the end
-	fn _push(&mut self, item: T) -> Result<(), T> {
+	pub(crate) fn _push(&mut self, item: T) -> Result<(), T> {
 		if !self.is_full() {
 			let len = self.len();
 
@@ -131,6 +140,34 @@ impl<T, const N: usize> BArrayVec<T, N> {
 			}
 		}
 	}
+	// INTERNAL: remove item at index and shift the tail left
+	//
+	// `len` is `NonZeroU8` so a node can never report itself empty through it; instead the
+	// second element of the tuple tells the caller "this was the last item, the node is now
+	// logically empty" and it is up to the caller to get rid of the node.
+	pub(crate) fn _remove(&mut self, index: usize) -> (T, bool) {
+		if index >= self.len() {
+			panic!(
+				"remove index out of bounds (index is {} but length is {})",
+				index,
+				self.len()
+			);
+		}
+
+		let ptr = unsafe { self.as_mut_ptr().add(index) };
+		let item = unsafe { ptr::read(ptr) };
+
+		if self.len() == 1 {
+			(item, true)
+		} else {
+			unsafe {
+				ptr::copy(ptr.add(1), ptr, self.len() - index - 1);
+				self.set_len(self.len() - 1);
+			}
+
+			(item, false)
+		}
+	}
 	// INTERNAL: insert item at index and shift items right possibly popping the last element
 	fn _insert(&mut self, index: usize, item: T) -> Option<T> {
 		if !(index < self.len()) {
@@ -203,13 +240,88 @@ where
 	}
 }
 
+/// An owning iterator over the items of a [`BArrayVec`], in order
+pub struct IntoIter<T, const N: usize> {
+	buf: [MaybeUninit<T>; N],
+	front: usize,
+	back: usize,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		if self.front < self.back {
+			let item = unsafe { self.buf[self.front].assume_init_read() };
+
+			self.front += 1;
+
+			Some(item)
+		} else {
+			None
+		}
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+
+		(len, Some(len))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+	fn next_back(&mut self) -> Option<T> {
+		if self.front < self.back {
+			self.back -= 1;
+
+			Some(unsafe { self.buf[self.back].assume_init_read() })
+		} else {
+			None
+		}
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+	fn len(&self) -> usize {
+		self.back - self.front
+	}
+}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+	fn drop(&mut self) {
+		for item in &mut self.buf[self.front..self.back] {
+			unsafe { item.assume_init_drop() };
+		}
+	}
+}
+
+impl<T, const N: usize> IntoIterator for BArrayVec<T, N> {
+	type Item = T;
+	type IntoIter = IntoIter<T, N>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		let len = self.len();
+		// `BArrayVec` has its own `Drop` impl, so `buf` has to be read out and `self` forgotten
+		// rather than left to fall out of scope - otherwise its contents would be dropped here
+		// and again by the `IntoIter` they're being handed off to
+		let buf = unsafe { ptr::read(&self.buf) };
+
+		std::mem::forget(self);
+
+		IntoIter {
+			buf,
+			front: 0,
+			back: len,
+		}
+	}
+}
+
 const _ASSERT_NULL_OPTIMIZED: () = {
 	use std::mem::size_of;
 
 	type L = BArrayVec<i32, 5>;
 
 	if size_of::<L>() != size_of::<Option<L>>() {
-		panic!("`List<T, N>` is not null optimized");
+		panic!("`BArrayVec<T, N>` is not null optimized");
 	}
 };
 
@@ -223,7 +335,7 @@ fn test_push() {
 
 	assert_eq!(&*this, &[100, 101, 102, 103, 104]);
 
-	let old = this;
+	let old = this.clone();
 
 	assert_eq!(this._push(105), Err(105));
 	assert_eq!(this, old);
@@ -239,7 +351,7 @@ fn test_push_front() {
 
 	assert_eq!(&*this, &[104, 103, 102, 101, 100]);
 
-	let old = this;
+	let old = this.clone();
 
 	assert_eq!(this._push_front(105), Err(105));
 	assert_eq!(this, old);
@@ -260,10 +372,94 @@ fn test_insert() {
 
 	assert_eq!(&*this, &[-102, -101, 100, 101, 102]);
 
-	let old = this;
+	let old = this.clone();
 
 	assert_eq!(this.insert(-200), Err((-200, AbsoluteOrdering::Less)));
 	assert_eq!(this.insert(200), Err((200, AbsoluteOrdering::Greater)));
 
 	assert_eq!(this, old);
 }
+
+#[test]
+fn test_remove() {
+	let mut this = BArrayVec::<i32, 5>::new(100);
+
+	for i in 101..105 {
+		this._push(i).unwrap();
+	}
+
+	assert_eq!(&*this, &[100, 101, 102, 103, 104]);
+
+	assert_eq!(this._remove(2), (102, false));
+
+	assert_eq!(&*this, &[100, 101, 103, 104]);
+
+	assert_eq!(this._remove(0), (100, false));
+
+	assert_eq!(&*this, &[101, 103, 104]);
+
+	let mut single = BArrayVec::<i32, 5>::new(42);
+
+	assert_eq!(single._remove(0), (42, true));
+}
+
+#[test]
+fn test_into_iter_drops_remaining() {
+	use std::cell::Cell;
+
+	#[derive(Debug)]
+	struct DropCounter<'a>(i32, &'a Cell<usize>);
+
+	impl<'a> Drop for DropCounter<'a> {
+		fn drop(&mut self) {
+			self.1.set(self.1.get() + 1);
+		}
+	}
+
+	let count = Cell::new(0);
+	let mut this = BArrayVec::<DropCounter, 5>::new(DropCounter(100, &count));
+
+	for i in 101..105 {
+		this._push(DropCounter(i, &count)).unwrap();
+	}
+
+	let mut iter = this.into_iter();
+
+	let first = iter.next().unwrap();
+	let last = iter.next_back().unwrap();
+
+	assert_eq!(first.0, 100);
+	assert_eq!(last.0, 104);
+	assert_eq!(count.get(), 0);
+
+	drop(first);
+	drop(last);
+	drop(iter);
+
+	assert_eq!(count.get(), 5);
+}
+
+#[test]
+fn test_drop_drops_remaining_items() {
+	use std::cell::Cell;
+
+	#[derive(Debug)]
+	struct DropCounter<'a>(&'a Cell<usize>);
+
+	impl<'a> Drop for DropCounter<'a> {
+		fn drop(&mut self) {
+			self.0.set(self.0.get() + 1);
+		}
+	}
+
+	let count = Cell::new(0);
+	let mut this = BArrayVec::<DropCounter, 5>::new(DropCounter(&count));
+
+	for _ in 0..4 {
+		this._push(DropCounter(&count)).unwrap();
+	}
+
+	drop(this);
+
+	assert_eq!(count.get(), 5);
+}